@@ -1,5 +1,7 @@
 //! Color value types.
 
+use std::ops::Mul;
+
 use amethyst_core::specs::{Component, DenseVecStorage};
 
 use gfx::shade::{Formatted, ToUniform};
@@ -72,6 +74,15 @@ impl Default for Rgba {
     }
 }
 
+/// Multiplies two colors component-wise, which is how a tint is applied to a color.
+impl Mul for Rgba {
+    type Output = Rgba;
+
+    fn mul(self, Rgba(r, g, b, a): Rgba) -> Rgba {
+        Rgba(self.0 * r, self.1 * g, self.2 * b, self.3 * a)
+    }
+}
+
 impl Component for Rgba {
     type Storage = DenseVecStorage<Self>;
 }
@@ -147,3 +158,16 @@ impl From<Rgba> for vec4 {
         [r, g, b, a].into()
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::Rgba;
+
+    #[test]
+    fn mul_multiplies_component_wise() {
+        assert_eq!(
+            Rgba(0.25, 0.5, 0.25, 0.5),
+            Rgba(0.5, 1.0, 0.25, 1.0) * Rgba(0.5, 0.5, 1.0, 0.5)
+        );
+    }
+}
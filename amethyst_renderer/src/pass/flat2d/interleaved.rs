@@ -471,6 +471,12 @@ impl TextureBatch {
                     let pos = transform
                         * Vector4::new(-sprite_data.offsets[0], -sprite_data.offsets[1], 0.0, 1.0);
 
+                    // The sprite sheet tint is applied on top of the entity's own color.
+                    let rgba = match sprite_data.tint {
+                        Some(tint) => Some(rgba.unwrap_or(Rgba::WHITE) * tint),
+                        None => *rgba,
+                    };
+
                     (
                         dir_x, dir_y, pos, uv_left, uv_right, uv_top, uv_bottom, rgba,
                     )
@@ -501,7 +507,7 @@ impl TextureBatch {
                     let pos = transform * Vector4::new(1.0, 1.0, 0.0, 1.0);
 
                     (
-                        dir_x, dir_y, pos, uv_left, uv_right, uv_top, uv_bottom, rgba,
+                        dir_x, dir_y, pos, uv_left, uv_right, uv_top, uv_bottom, *rgba,
                     )
                 }
             };
@@ -5,7 +5,7 @@ use amethyst_assets::{Asset, Handle, ProcessingState, SimpleFormat};
 use amethyst_core::specs::prelude::{Component, DenseVecStorage, VecStorage};
 use amethyst_error::Error;
 
-use crate::{error, Texture};
+use crate::{error, Rgba, Texture};
 
 /// An asset handle to sprite sheet metadata.
 pub type SpriteSheetHandle = Handle<SpriteSheet>;
@@ -62,6 +62,11 @@ pub struct Sprite {
     pub offsets: [f32; 2],
     /// Texture coordinates of the sprite
     pub tex_coords: TextureCoordinates,
+    /// Color multiplied with the sprite when rendering, in addition to any `Rgba` component on
+    /// the entity. `None` leaves the sprite untinted. As with `Rgba`, an alpha below 1.0 is only
+    /// blended if the entity also has the `Transparent` component.
+    #[serde(default)]
+    pub tint: Option<Rgba>,
 }
 
 /// Texture coordinates of the sprite
@@ -142,6 +147,7 @@ impl Sprite {
             height: sprite_h as f32,
             offsets,
             tex_coords,
+            tint: None,
         }
     }
 }
@@ -159,6 +165,7 @@ impl From<((f32, f32), [f32; 2], [f32; 4])> for Sprite {
             height,
             offsets,
             tex_coords: TextureCoordinates::from(tex_coords),
+            tint: None,
         }
     }
 }
@@ -215,6 +222,8 @@ struct SpritePosition {
     pub height: u32,
     /// Number of pixels to shift the sprite to the left and down relative to the entity holding it
     pub offsets: Option<[f32; 2]>,
+    /// Color multiplied with the sprite when rendering
+    pub tint: Option<Rgba>,
 }
 
 /// Structure acting as scaffolding for serde when loading a spritesheet file.
@@ -228,6 +237,29 @@ struct SerializedSpriteSheet {
     pub sprites: Vec<SpritePosition>,
 }
 
+impl SerializedSpriteSheet {
+    /// Converts the pixel positions of the sprites into `Sprite`s.
+    fn into_sprites(self) -> Vec<Sprite> {
+        let mut sprites: Vec<Sprite> = Vec::with_capacity(self.sprites.len());
+        for sp in self.sprites {
+            let sprite = Sprite {
+                tint: sp.tint,
+                ..Sprite::from_pixel_values(
+                    self.spritesheet_width as u32,
+                    self.spritesheet_height as u32,
+                    sp.width as u32,
+                    sp.height as u32,
+                    sp.x as u32,
+                    sp.y as u32,
+                    sp.offsets.unwrap_or([0.0; 2]),
+                )
+            };
+            sprites.push(sprite);
+        }
+        sprites
+    }
+}
+
 /// Allows loading of sprite sheets in RON format.
 ///
 /// This format allows to conveniently load a sprite sheet from a RON file.
@@ -252,6 +284,8 @@ struct SerializedSpriteSheet {
 ///             height: 16.0,
 ///             // Number of pixels to shift the sprite to the left and down relative to the entity holding it when rendering
 ///             offsets: (0.0, 0.0), // This is optional and defaults to (0.0, 0.0)
+///             // Color multiplied with the sprite when rendering, alpha requires `Transparent`
+///             tint: Some((1.0, 0.5, 0.5, 1.0)), // This is optional and defaults to None
 ///         ),
 ///         (
 ///             x: 16.0,
@@ -301,26 +335,18 @@ impl SimpleFormat<SpriteSheet> for SpriteSheetFormat {
         let sheet: SerializedSpriteSheet =
             from_ron_bytes(&bytes).map_err(|_| error::Error::LoadSpritesheetError)?;
 
-        let mut sprites: Vec<Sprite> = Vec::with_capacity(sheet.sprites.len());
-        for sp in sheet.sprites {
-            let sprite = Sprite::from_pixel_values(
-                sheet.spritesheet_width as u32,
-                sheet.spritesheet_height as u32,
-                sp.width as u32,
-                sp.height as u32,
-                sp.x as u32,
-                sp.y as u32,
-                sp.offsets.unwrap_or([0.0; 2]),
-            );
-            sprites.push(sprite);
-        }
+        let sprites = sheet.into_sprites();
         Ok(SpriteSheet { texture, sprites })
     }
 }
 
 #[cfg(test)]
 mod test {
-    use super::{Sprite, TextureCoordinates};
+    use ron::de::from_str as from_ron_str;
+
+    use crate::Rgba;
+
+    use super::{SerializedSpriteSheet, Sprite, TextureCoordinates};
 
     #[test]
     fn texture_coordinates_from_tuple_maps_fields_correctly() {
@@ -361,6 +387,7 @@ mod test {
                     bottom: 0.75,
                     top: 1.0,
                 },
+                tint: None,
             },
             ((10., 40.), [5., 20.], [0.0, 0.5, 0.75, 1.0]).into()
         );
@@ -379,6 +406,7 @@ mod test {
                     bottom: 0.75,
                     top: 1.0,
                 },
+                tint: None,
             },
             ((10., 40.), [0.0, 0.5, 0.75, 1.0]).into()
         );
@@ -405,4 +433,38 @@ mod test {
             )
         );
     }
+
+    #[test]
+    fn sprite_sheet_format_reads_tint() {
+        let sheet: SerializedSpriteSheet = from_ron_str(
+            "(
+                spritesheet_width: 32,
+                spritesheet_height: 16,
+                sprites: [
+                    (x: 0, y: 0, width: 16, height: 16, tint: Some((1.0, 0.5, 0.5, 1.0))),
+                ],
+            )",
+        )
+        .expect("Failed to deserialize sprite sheet");
+
+        let sprites = sheet.into_sprites();
+        assert_eq!(Some(Rgba(1.0, 0.5, 0.5, 1.0)), sprites[0].tint);
+    }
+
+    #[test]
+    fn sprite_sheet_format_tint_defaults_to_none() {
+        let sheet: SerializedSpriteSheet = from_ron_str(
+            "(
+                spritesheet_width: 32,
+                spritesheet_height: 16,
+                sprites: [
+                    (x: 0, y: 0, width: 16, height: 16),
+                ],
+            )",
+        )
+        .expect("Failed to deserialize sprite sheet");
+
+        let sprites = sheet.into_sprites();
+        assert_eq!(None, sprites[0].tint);
+    }
 }
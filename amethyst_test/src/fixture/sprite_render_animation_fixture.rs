@@ -96,6 +96,7 @@ impl SpriteRenderAnimationFixture {
                 height: 10.0,
                 offsets: [5.; 2],
                 tex_coords: [0.0, 1.0, 0.0, 1.0].into(),
+                tint: None,
             }],
         }
     }